@@ -1,28 +1,40 @@
+use rust_gdb_example::registry::SanctuaryRegistry;
 use rust_gdb_example::{Animal, AnimalType};
 
 fn main() {
-    let animals: Vec<Animal> = vec![
+    let mut registry = SanctuaryRegistry::new();
+    registry.add_animal_to_section(
+        "east-wing",
         Animal {
             kind: AnimalType::Cat,
             name: "Chip".to_string(),
             age: 4,
         },
+    );
+    registry.add_animal_to_section(
+        "east-wing",
         Animal {
             kind: AnimalType::Cat,
             name: "Nacho".to_string(),
             age: 6,
         },
+    );
+    registry.add_animal_to_section(
+        "east-wing",
         Animal {
             kind: AnimalType::Dog,
             name: "Taco".to_string(),
             age: 2,
         },
-    ];
+    );
 
+    let animals = registry.get_animals_in_section("east-wing");
     get_chip(&animals);
 }
 
 fn get_chip(animals: &[Animal]) {
+    let mut animals = animals.to_vec();
+    animals.sort();
     let chip = animals.first();
 
     println!("chip: {chip:?}");
@@ -0,0 +1,19 @@
+//! Embeds a `.debug_gdb_scripts` section pointing at `gdb/load_rust_gdb_example_printers.py`
+//! so GDB auto-loads this crate's pretty-printers and xmethods when it loads
+//! a debug build of this crate (see `gdb/` for the scripts themselves).
+
+const GDB_SCRIPT_PATH: &str = "gdb/load_rust_gdb_example_printers.py";
+
+#[used]
+#[link_section = ".debug_gdb_scripts"]
+static LOAD_GDB_SCRIPTS: [u8; GDB_SCRIPT_PATH.len() + 2] = {
+    let mut buf = [0u8; GDB_SCRIPT_PATH.len() + 2];
+    buf[0] = 1; // marker byte: a path to a Python script follows
+    let bytes = GDB_SCRIPT_PATH.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        buf[i + 1] = bytes[i];
+        i += 1;
+    }
+    buf
+};
@@ -0,0 +1,208 @@
+//! Shared types for the `rust-gdb-example` walkthrough crate.
+
+mod gdb_support;
+pub mod registry;
+pub mod store;
+
+use std::collections::HashMap;
+use std::fmt;
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum AnimalType {
+    Cat,
+    Dog,
+}
+
+/// An animal kind that doesn't match any known `AnimalType` variant.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseAnimalTypeError(String);
+
+impl fmt::Display for ParseAnimalTypeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown animal kind: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for ParseAnimalTypeError {}
+
+impl FromStr for AnimalType {
+    type Err = ParseAnimalTypeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "cat" => Ok(AnimalType::Cat),
+            "dog" => Ok(AnimalType::Dog),
+            other => Err(ParseAnimalTypeError(other.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for AnimalType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            AnimalType::Cat => "cat",
+            AnimalType::Dog => "dog",
+        };
+        write!(f, "{s}")
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Animal {
+    pub kind: AnimalType,
+    pub name: String,
+    pub age: u32,
+}
+
+/// An animal record (`kind,name,age`) that couldn't be parsed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseAnimalError {
+    Kind(ParseAnimalTypeError),
+    Age(ParseIntError),
+    Malformed(String),
+}
+
+impl fmt::Display for ParseAnimalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseAnimalError::Kind(e) => write!(f, "invalid animal kind: {e}"),
+            ParseAnimalError::Age(e) => write!(f, "invalid animal age: {e}"),
+            ParseAnimalError::Malformed(s) => write!(f, "malformed animal record: {s:?}"),
+        }
+    }
+}
+
+impl std::error::Error for ParseAnimalError {}
+
+impl From<ParseAnimalTypeError> for ParseAnimalError {
+    fn from(e: ParseAnimalTypeError) -> Self {
+        ParseAnimalError::Kind(e)
+    }
+}
+
+impl From<ParseIntError> for ParseAnimalError {
+    fn from(e: ParseIntError) -> Self {
+        ParseAnimalError::Age(e)
+    }
+}
+
+impl FromStr for Animal {
+    type Err = ParseAnimalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split(',');
+        let kind = parts
+            .next()
+            .ok_or_else(|| ParseAnimalError::Malformed(s.to_string()))?
+            .parse::<AnimalType>()?;
+        let name = parts
+            .next()
+            .ok_or_else(|| ParseAnimalError::Malformed(s.to_string()))?
+            .to_string();
+        let age = parts
+            .next()
+            .ok_or_else(|| ParseAnimalError::Malformed(s.to_string()))?
+            .parse::<u32>()?;
+        if parts.next().is_some() {
+            return Err(ParseAnimalError::Malformed(s.to_string()));
+        }
+        Ok(Animal { kind, name, age })
+    }
+}
+
+impl fmt::Display for Animal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{},{}", self.kind, self.name, self.age)
+    }
+}
+
+pub(crate) fn same_animal(a: &Animal, b: &Animal) -> bool {
+    a.name == b.name && a.kind == b.kind
+}
+
+/// Buckets `animals` by their `kind`, disregarding name and age.
+pub fn group_by_kind(animals: &[Animal]) -> HashMap<AnimalType, Vec<&Animal>> {
+    let mut groups: HashMap<AnimalType, Vec<&Animal>> = HashMap::new();
+    for animal in animals {
+        groups.entry(animal.kind.clone()).or_default().push(animal);
+    }
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_animal_type_case_insensitively() {
+        assert_eq!("cat".parse::<AnimalType>().unwrap(), AnimalType::Cat);
+        assert_eq!("DOG".parse::<AnimalType>().unwrap(), AnimalType::Dog);
+        assert!("iguana".parse::<AnimalType>().is_err());
+    }
+
+    #[test]
+    fn round_trips_animal_from_str() {
+        let animal: Animal = "cat,Chip,4".parse().unwrap();
+        assert_eq!(
+            animal,
+            Animal {
+                kind: AnimalType::Cat,
+                name: "Chip".to_string(),
+                age: 4,
+            }
+        );
+    }
+
+    #[test]
+    fn displays_in_the_format_from_str_accepts() {
+        let animal: Animal = "cat,Chip,4".parse().unwrap();
+        assert_eq!(animal.to_string(), "cat,Chip,4");
+        assert_eq!(animal.to_string().parse::<Animal>().unwrap(), animal);
+    }
+
+    #[test]
+    fn rejects_malformed_animal_records() {
+        assert!("cat,Chip".parse::<Animal>().is_err());
+        assert!("cat,Chip,4,extra".parse::<Animal>().is_err());
+        assert!("cat,Chip,old".parse::<Animal>().is_err());
+    }
+
+    #[test]
+    fn sorts_by_kind_then_name_then_age() {
+        let mut animals: Vec<Animal> = vec![
+            "dog,Taco,2".parse().unwrap(),
+            "cat,Nacho,6".parse().unwrap(),
+            "cat,Chip,4".parse().unwrap(),
+        ];
+        animals.sort();
+
+        let names: Vec<&str> = animals.iter().map(|a| a.name.as_str()).collect();
+        assert_eq!(names, vec!["Chip", "Nacho", "Taco"]);
+    }
+
+    #[test]
+    fn groups_by_kind_ignoring_name_and_age() {
+        let animals: Vec<Animal> = vec![
+            "cat,Chip,4".parse().unwrap(),
+            "cat,Nacho,6".parse().unwrap(),
+            "dog,Taco,2".parse().unwrap(),
+        ];
+
+        let groups = group_by_kind(&animals);
+
+        let mut cats: Vec<&str> = groups[&AnimalType::Cat]
+            .iter()
+            .map(|a| a.name.as_str())
+            .collect();
+        cats.sort();
+        assert_eq!(cats, vec!["Chip", "Nacho"]);
+
+        let dogs: Vec<&str> = groups[&AnimalType::Dog]
+            .iter()
+            .map(|a| a.name.as_str())
+            .collect();
+        assert_eq!(dogs, vec!["Taco"]);
+    }
+}
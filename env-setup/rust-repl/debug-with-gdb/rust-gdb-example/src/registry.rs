@@ -0,0 +1,120 @@
+//! A managed collection of animals, organized into named sanctuary sections.
+
+use std::collections::HashMap;
+
+use crate::{same_animal, Animal};
+
+/// Animals housed in a sanctuary, grouped by section name.
+#[derive(Clone, Debug, Default)]
+pub struct SanctuaryRegistry {
+    sections: HashMap<String, Vec<Animal>>,
+}
+
+impl SanctuaryRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `animal` to `section`, creating the section if it doesn't exist yet.
+    /// An animal already present in the section (same kind and name) is skipped.
+    pub fn add_animal_to_section(&mut self, section: &str, animal: Animal) {
+        let animals = self.sections.entry(section.to_string()).or_default();
+        if animals.iter().any(|existing| same_animal(existing, &animal)) {
+            return;
+        }
+        animals.push(animal);
+    }
+
+    /// Returns the animals housed in `section`, sorted alphabetically by name.
+    /// Returns an empty vec for an unknown section.
+    pub fn get_animals_in_section(&self, section: &str) -> Vec<Animal> {
+        let mut animals = self.sections.get(section).cloned().unwrap_or_default();
+        animals.sort_by(|a, b| a.name.cmp(&b.name));
+        animals
+    }
+
+    /// Returns every section's animals, each sorted alphabetically by name.
+    pub fn get_all_animals(&self) -> HashMap<String, Vec<Animal>> {
+        self.sections
+            .iter()
+            .map(|(section, animals)| {
+                let mut animals = animals.clone();
+                animals.sort_by(|a, b| a.name.cmp(&b.name));
+                (section.clone(), animals)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AnimalType;
+
+    fn chip() -> Animal {
+        Animal {
+            kind: AnimalType::Cat,
+            name: "Chip".to_string(),
+            age: 4,
+        }
+    }
+
+    fn nacho() -> Animal {
+        Animal {
+            kind: AnimalType::Cat,
+            name: "Nacho".to_string(),
+            age: 6,
+        }
+    }
+
+    #[test]
+    fn creates_the_section_on_first_add() {
+        let mut registry = SanctuaryRegistry::new();
+        registry.add_animal_to_section("east-wing", chip());
+
+        assert_eq!(registry.get_animals_in_section("east-wing"), vec![chip()]);
+    }
+
+    #[test]
+    fn dedups_by_name_and_kind() {
+        let mut registry = SanctuaryRegistry::new();
+        registry.add_animal_to_section("east-wing", chip());
+        registry.add_animal_to_section(
+            "east-wing",
+            Animal {
+                age: 99,
+                ..chip()
+            },
+        );
+
+        assert_eq!(registry.get_animals_in_section("east-wing"), vec![chip()]);
+    }
+
+    #[test]
+    fn unknown_section_is_empty() {
+        let registry = SanctuaryRegistry::new();
+        assert_eq!(registry.get_animals_in_section("nowhere"), Vec::new());
+    }
+
+    #[test]
+    fn section_animals_are_sorted_by_name() {
+        let mut registry = SanctuaryRegistry::new();
+        registry.add_animal_to_section("east-wing", nacho());
+        registry.add_animal_to_section("east-wing", chip());
+
+        assert_eq!(
+            registry.get_animals_in_section("east-wing"),
+            vec![chip(), nacho()]
+        );
+    }
+
+    #[test]
+    fn get_all_animals_sorts_each_section() {
+        let mut registry = SanctuaryRegistry::new();
+        registry.add_animal_to_section("east-wing", nacho());
+        registry.add_animal_to_section("east-wing", chip());
+
+        let all = registry.get_all_animals();
+        assert_eq!(all.get("east-wing"), Some(&vec![chip(), nacho()]));
+    }
+}
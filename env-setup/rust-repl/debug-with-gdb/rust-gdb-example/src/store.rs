@@ -0,0 +1,197 @@
+//! File-backed key/value persistence for [`Animal`]s, so sanctuary state
+//! survives restarts without pulling in a serialization crate.
+//!
+//! Records are stored one per line, reusing [`Animal`]'s `FromStr`/`Display`
+//! impls rather than a binary or JSON format: `S:<kind>,<name>,<age>` for a
+//! live record, `D:<name>` as a tombstone for a removed one.
+
+use std::collections::HashMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::PathBuf;
+
+use crate::Animal;
+
+/// An append-only, file-backed store of [`Animal`]s keyed by name.
+pub struct AnimalStore {
+    path: PathBuf,
+    records: HashMap<String, Animal>,
+}
+
+impl AnimalStore {
+    /// Opens the store backed by `path`, loading any records already there.
+    /// The file doesn't need to exist yet; it's created on the first write.
+    pub fn open(path: impl Into<PathBuf>) -> io::Result<Self> {
+        let mut store = AnimalStore {
+            path: path.into(),
+            records: HashMap::new(),
+        };
+        store.load()?;
+        Ok(store)
+    }
+
+    /// Sets `animal.name` to `animal`, appending the change to the log.
+    /// Keyed by `animal.name` rather than a separate parameter because that's
+    /// the only key the on-disk format (and thus `load`) actually knows about.
+    pub fn set(&mut self, animal: Animal) -> io::Result<()> {
+        self.append(&format!("S:{animal}\n"))?;
+        self.records.insert(animal.name.clone(), animal);
+        Ok(())
+    }
+
+    /// Returns the animal stored under `name`, if any.
+    pub fn get(&self, name: &str) -> Option<Animal> {
+        self.records.get(name).cloned()
+    }
+
+    /// Removes `name` from the store, appending a tombstone to the log.
+    pub fn remove(&mut self, name: &str) -> io::Result<()> {
+        self.append(&format!("D:{name}\n"))?;
+        self.records.remove(name);
+        Ok(())
+    }
+
+    /// Replays the log from disk, replacing the current in-memory records.
+    /// Unparseable lines are skipped rather than failing the whole load.
+    pub fn load(&mut self) -> io::Result<()> {
+        self.records.clear();
+        let file = match File::open(&self.path) {
+            Ok(file) => file,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(()),
+            Err(e) => return Err(e),
+        };
+        for line in BufReader::new(file).lines() {
+            let line = line?;
+            if let Some(rest) = line.strip_prefix("S:") {
+                if let Ok(animal) = rest.parse::<Animal>() {
+                    self.records.insert(animal.name.clone(), animal);
+                }
+            } else if let Some(name) = line.strip_prefix("D:") {
+                self.records.remove(name);
+            }
+        }
+        Ok(())
+    }
+
+    /// Rewrites the log to hold exactly one `set` entry per current record,
+    /// so repeated sets/removes of the same key don't grow the file forever.
+    pub fn flush(&mut self) -> io::Result<()> {
+        let mut contents = String::new();
+        for animal in self.records.values() {
+            contents.push_str(&format!("S:{animal}\n"));
+        }
+        fs::write(&self.path, contents)
+    }
+
+    fn append(&self, line: &str) -> io::Result<()> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        file.write_all(line.as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AnimalType;
+
+    fn temp_store_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "rust_gdb_example_store_test_{name}_{}.log",
+            std::process::id()
+        ))
+    }
+
+    fn chip() -> Animal {
+        Animal {
+            kind: AnimalType::Cat,
+            name: "Chip".to_string(),
+            age: 4,
+        }
+    }
+
+    #[test]
+    fn set_and_get_round_trip() {
+        let path = temp_store_path("round_trip");
+        let _ = fs::remove_file(&path);
+
+        let mut store = AnimalStore::open(&path).unwrap();
+        store.set(chip()).unwrap();
+
+        assert_eq!(store.get("Chip"), Some(chip()));
+        assert_eq!(store.get("Nacho"), None);
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn reopening_replays_the_log() {
+        let path = temp_store_path("replay");
+        let _ = fs::remove_file(&path);
+
+        {
+            let mut store = AnimalStore::open(&path).unwrap();
+            store.set(chip()).unwrap();
+        }
+
+        let reopened = AnimalStore::open(&path).unwrap();
+        assert_eq!(reopened.get("Chip"), Some(chip()));
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn remove_drops_the_record_after_reload() {
+        let path = temp_store_path("remove");
+        let _ = fs::remove_file(&path);
+
+        {
+            let mut store = AnimalStore::open(&path).unwrap();
+            store.set(chip()).unwrap();
+            store.remove("Chip").unwrap();
+        }
+
+        let reopened = AnimalStore::open(&path).unwrap();
+        assert_eq!(reopened.get("Chip"), None);
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn flush_compacts_repeated_writes() {
+        let path = temp_store_path("compact");
+        let _ = fs::remove_file(&path);
+
+        let mut store = AnimalStore::open(&path).unwrap();
+        for age in 0..5 {
+            store
+                .set(Animal {
+                    kind: AnimalType::Cat,
+                    name: "Chip".to_string(),
+                    age,
+                })
+                .unwrap();
+        }
+        let size_before_flush = fs::metadata(&path).unwrap().len();
+
+        store.flush().unwrap();
+        let size_after_flush = fs::metadata(&path).unwrap().len();
+
+        assert!(size_after_flush < size_before_flush);
+        assert_eq!(
+            store.get("Chip"),
+            Some(Animal {
+                kind: AnimalType::Cat,
+                name: "Chip".to_string(),
+                age: 4,
+            })
+        );
+
+        let reopened = AnimalStore::open(&path).unwrap();
+        assert_eq!(reopened.get("Chip"), store.get("Chip"));
+
+        fs::remove_file(&path).unwrap();
+    }
+}
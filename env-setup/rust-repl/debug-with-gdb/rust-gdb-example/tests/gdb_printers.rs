@@ -0,0 +1,39 @@
+use std::path::PathBuf;
+use std::process::Command;
+
+/// Runs the bundled GDB pretty-printers against a compiled debug binary and
+/// checks their rendering. Skipped (not failed) when `gdb` isn't on `PATH`,
+/// since not every machine running this walkthrough has it installed.
+#[test]
+fn pretty_printers_render_animal_and_animal_type() {
+    if Command::new("gdb").arg("--version").output().is_err() {
+        eprintln!("skipping: gdb not found on PATH");
+        return;
+    }
+
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+
+    let status = Command::new(env!("CARGO"))
+        .args(["build", "--example", "basic"])
+        .current_dir(&manifest_dir)
+        .status()
+        .expect("failed to build the `basic` example");
+    assert!(status.success());
+
+    let binary = manifest_dir.join("target/debug/examples/basic");
+    let script = manifest_dir.join("gdb/session.gdb");
+
+    let output = Command::new("gdb")
+        .args(["--batch", "-x"])
+        .arg(&script)
+        .arg(&binary)
+        .current_dir(&manifest_dir)
+        .output()
+        .expect("failed to run gdb");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains(r#"Animal(Cat "Chip" age=4)"#),
+        "unexpected gdb output:\n{stdout}"
+    );
+}